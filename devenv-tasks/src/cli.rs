@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use crate::{Config, Error, OutputFormat, TaskRecord, TasksUi, VerbosityLevel};
+
+/// Arguments shared by the `devenv tasks run` and `devenv tasks status`
+/// commands, applied to a freshly constructed [`TasksUi`].
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct RunArgs {
+    /// Tail the named task's live output at the bottom of the TUI, instead of
+    /// only showing it once the task fails. Press `f` while running to cycle
+    /// between currently running tasks.
+    #[arg(long)]
+    pub follow: Option<String>,
+
+    /// Emit newline-delimited JSON status events on stdout instead of the
+    /// interactive status table, for CI and editor tooling to consume.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl RunArgs {
+    /// Apply `--follow`/`--json` to a freshly constructed [`TasksUi`].
+    pub fn apply(&self, ui: &mut TasksUi) {
+        if self.follow.is_some() {
+            ui.set_follow(self.follow.clone());
+        }
+        if self.json {
+            ui.set_output_format(OutputFormat::Json);
+        }
+    }
+}
+
+/// `devenv tasks status`: report the outcome of the most recent completed
+/// `devenv tasks run` at `db_path`, without re-running anything. Tasks that
+/// run persists its status only once, after every task has finished, so a
+/// run still in progress won't be reflected here until it completes; any
+/// task not yet seen in a persisted run is reported `Pending`.
+pub async fn status(
+    config: Config,
+    db_path: PathBuf,
+    verbosity: VerbosityLevel,
+) -> Result<Vec<TaskRecord>, Error> {
+    let ui = TasksUi::new_with_db_path(config, db_path, verbosity).await?;
+    Ok(ui.task_records().await)
+}
@@ -0,0 +1,81 @@
+mod cli;
+mod tasks;
+mod types;
+mod ui;
+
+pub use cli::{status, RunArgs};
+pub use tasks::Tasks;
+pub use types::{
+    Failure, OutputStream, Skipped, Task, TaskCompleted, TaskControl, TaskNode, TaskStatus,
+};
+pub use ui::{TaskRecord, TaskState, TasksStatus, TasksUi};
+
+use std::collections::HashMap;
+
+/// How much task output to show while running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerbosityLevel {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+/// How status updates are reported by [`TasksUi::run`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// ANSI status table (or plain-text transition lines when not a TTY).
+    Text,
+    /// Newline-delimited JSON events on stdout, for CI and editor tooling.
+    Json,
+}
+
+/// One task's configuration, as declared in `devenv.nix`.
+#[derive(Debug, Clone)]
+pub struct TaskConfig {
+    pub name: String,
+    pub depends_on: Vec<String>,
+    pub command: Option<Vec<String>>,
+}
+
+/// The task graph to build and run: every task involved (including
+/// transitive dependencies) plus which of them were explicitly requested on
+/// the command line.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    pub tasks: Vec<TaskConfig>,
+    pub requested: Vec<String>,
+}
+
+/// Arbitrary key/value outputs produced by a task, consumed by whatever
+/// depends on it.
+pub type Outputs = HashMap<String, serde_json::Value>;
+
+/// Errors that can occur building or running the task graph.
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    UnknownDependency { task: String, dependency: String },
+    DependencyCycle { task: String },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::UnknownDependency { task, dependency } => {
+                write!(f, "task '{task}' depends on unknown task '{dependency}'")
+            }
+            Error::DependencyCycle { task } => {
+                write!(f, "dependency cycle detected at task '{task}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
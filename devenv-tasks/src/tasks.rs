@@ -0,0 +1,535 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::Direction;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::{mpsc, Mutex, Notify, RwLock};
+
+use crate::types::{
+    Failure, OutputStream, Skipped, Task, TaskCompleted, TaskControl, TaskNode, TaskStatus,
+};
+use crate::{Config, Error, Outputs, VerbosityLevel};
+
+/// The task dependency graph and its run state, shared behind an `Arc` so the
+/// UI can poll it concurrently with the background run loop that drives it.
+pub struct Tasks {
+    pub(crate) graph: Graph<RwLock<TaskNode>, ()>,
+    /// Topological run order, computed once at construction.
+    pub(crate) tasks_order: Vec<NodeIndex>,
+    /// Names of the tasks explicitly requested on the command line, for the
+    /// "Running tasks: ..." banner.
+    pub(crate) root_names: Vec<String>,
+    /// Longest task name, used to align the status table and summary line.
+    pub(crate) longest_task_name: usize,
+    /// Notified whenever any task transitions, so the UI can redraw instead
+    /// of polling.
+    pub(crate) notify_ui: Notify,
+    /// Transition requests from the UI (`p`/`r`/`c`/double Ctrl-C). Delivered
+    /// over a channel and applied by a background loop spawned in
+    /// [`Tasks::run`], rather than mutating task state directly from
+    /// `request_all`'s caller.
+    control_tx: mpsc::UnboundedSender<TaskControl>,
+    control_rx: Mutex<Option<mpsc::UnboundedReceiver<TaskControl>>>,
+    /// Where [`Tasks::run`] persists each task's final status once it
+    /// completes, and where [`Tasks::build`] reads a previous run's status
+    /// back from to seed a fresh graph. `None` for a plain [`Tasks::new`],
+    /// which never persists or loads anything.
+    db_path: Option<PathBuf>,
+    #[allow(dead_code)]
+    verbosity: VerbosityLevel,
+}
+
+impl Tasks {
+    pub async fn new(config: Config, verbosity: VerbosityLevel) -> Result<Self, Error> {
+        Self::build(config, None, verbosity)
+    }
+
+    /// Like [`Tasks::new`], but seeds each task's initial status from
+    /// whatever [`Tasks::run`] last persisted at `db_path` (if anything),
+    /// instead of leaving every task `Pending`. This is what lets `devenv
+    /// tasks status` report on a previous run without re-running it.
+    pub async fn new_with_db_path(
+        config: Config,
+        db_path: PathBuf,
+        verbosity: VerbosityLevel,
+    ) -> Result<Self, Error> {
+        Self::build(config, Some(db_path), verbosity)
+    }
+
+    fn build(
+        config: Config,
+        db_path: Option<PathBuf>,
+        verbosity: VerbosityLevel,
+    ) -> Result<Self, Error> {
+        let mut graph = Graph::new();
+        let mut by_name = HashMap::new();
+
+        for task in &config.tasks {
+            let index = graph.add_node(RwLock::new(TaskNode::new(Task {
+                name: task.name.clone(),
+                command: task.command.clone(),
+            })));
+            by_name.insert(task.name.clone(), index);
+        }
+
+        if let Some(path) = &db_path {
+            for persisted in load_snapshot(path) {
+                if let Some(&index) = by_name.get(&persisted.name) {
+                    graph[index].get_mut().status = persisted.state.into_task_status();
+                }
+            }
+        }
+
+        for task in &config.tasks {
+            let index = by_name[&task.name];
+            for dependency in &task.depends_on {
+                let dep_index =
+                    *by_name
+                        .get(dependency)
+                        .ok_or_else(|| Error::UnknownDependency {
+                            task: task.name.clone(),
+                            dependency: dependency.clone(),
+                        })?;
+                // Edge points dependency -> dependent, so a topological sort
+                // runs dependencies before the tasks that need them.
+                graph.add_edge(dep_index, index, ());
+            }
+        }
+
+        let tasks_order = petgraph::algo::toposort(&graph, None).map_err(|cycle| {
+            let name = graph[cycle.node_id()]
+                .try_read()
+                .map(|node| node.task.name.clone())
+                .unwrap_or_default();
+            Error::DependencyCycle { task: name }
+        })?;
+
+        let root_names = if config.requested.is_empty() {
+            config.tasks.iter().map(|task| task.name.clone()).collect()
+        } else {
+            config.requested.clone()
+        };
+        let longest_task_name = config
+            .tasks
+            .iter()
+            .map(|task| task.name.len())
+            .max()
+            .unwrap_or(0);
+
+        let (control_tx, control_rx) = mpsc::unbounded_channel();
+
+        Ok(Self {
+            graph,
+            tasks_order,
+            root_names,
+            longest_task_name,
+            notify_ui: Notify::new(),
+            control_tx,
+            control_rx: Mutex::new(Some(control_rx)),
+            db_path,
+            verbosity,
+        })
+    }
+
+    /// Enqueue a transition request for every task. Applied asynchronously by
+    /// the control loop spawned in [`Tasks::run`], so this never blocks on the
+    /// graph's per-task locks.
+    pub async fn request_all(&self, control: TaskControl) -> Result<(), Error> {
+        let _ = self.control_tx.send(control);
+        Ok(())
+    }
+
+    /// Apply one transition request to every task currently in a state where
+    /// it's valid, then wake the UI. A request that doesn't apply to a given
+    /// task's current status (e.g. pausing one that already completed) just
+    /// leaves that task alone.
+    ///
+    /// A cancel or abort that lands on a `Running`/`Paused` task also wakes
+    /// that task's `cancel` notify, which is what actually tells
+    /// `run_command` to kill the child -- without it, this method would only
+    /// ever update the status label while the real process kept running
+    /// until it exited on its own.
+    async fn apply_transition_all(&self, control: TaskControl) {
+        for &index in &self.tasks_order {
+            let mut node = self.graph[index].write().await;
+            let terminates_child = matches!(
+                (&node.status, control),
+                (
+                    TaskStatus::Running(_) | TaskStatus::Paused(_),
+                    TaskControl::Cancel | TaskControl::Abort
+                ) | (TaskStatus::Cancelling(_), TaskControl::Abort)
+            );
+
+            node.status = match (&node.status, control) {
+                (TaskStatus::Running(started), TaskControl::Pause) => TaskStatus::Paused(*started),
+                (TaskStatus::Paused(started), TaskControl::Resume) => TaskStatus::Running(*started),
+                (TaskStatus::Running(started), TaskControl::Cancel)
+                | (TaskStatus::Paused(started), TaskControl::Cancel) => {
+                    TaskStatus::Cancelling(*started)
+                }
+                (TaskStatus::Pending, TaskControl::Cancel) => {
+                    TaskStatus::Completed(TaskCompleted::Cancelled)
+                }
+                (TaskStatus::Running(_), TaskControl::Abort)
+                | (TaskStatus::Paused(_), TaskControl::Abort)
+                | (TaskStatus::Cancelling(_), TaskControl::Abort) => {
+                    TaskStatus::Completed(TaskCompleted::Cancelled)
+                }
+                (other, _) => other.clone(),
+            };
+
+            if terminates_child {
+                node.cancel.notify_waiters();
+            }
+        }
+        self.notify_ui.notify_waiters();
+    }
+
+    /// True once every dependency of `index` has reached a terminal status.
+    async fn dependencies_settled(&self, index: NodeIndex) -> bool {
+        for dependency in self.graph.neighbors_directed(index, Direction::Incoming) {
+            if !matches!(
+                self.graph[dependency].read().await.status,
+                TaskStatus::Completed(_)
+            ) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// True if any dependency of `index` didn't succeed (failed, was itself
+    /// dependency-failed, or was cancelled). When this holds, `index` is
+    /// marked `DependencyFailed` instead of running — this is how a cancel on
+    /// an upstream task propagates down into the tasks waiting on it, instead
+    /// of leaving them pending forever. A paused upstream task is not settled
+    /// (see [`Tasks::dependencies_settled`]), so its dependents stay pending.
+    async fn dependency_failed(&self, index: NodeIndex) -> bool {
+        for dependency in self.graph.neighbors_directed(index, Direction::Incoming) {
+            if !matches!(
+                self.graph[dependency].read().await.status,
+                TaskStatus::Completed(TaskCompleted::Success(_, _))
+                    | TaskStatus::Completed(TaskCompleted::Skipped(_))
+            ) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Run every task in the graph to completion, honouring dependency order
+    /// and any pause/resume/cancel/abort requests delivered to
+    /// [`Tasks::request_all`]. Persists every task's final status to
+    /// `db_path` (if set) before returning, so a later `devenv tasks status`
+    /// can report on this run. Returns the accumulated [`Outputs`] of every
+    /// task that succeeded.
+    pub async fn run(self: &Arc<Self>) -> Outputs {
+        let mut control_rx = self
+            .control_rx
+            .lock()
+            .await
+            .take()
+            .expect("Tasks::run called more than once");
+
+        let control_loop = {
+            let tasks = Arc::clone(self);
+            tokio::spawn(async move {
+                while let Some(control) = control_rx.recv().await {
+                    tasks.apply_transition_all(control).await;
+                }
+            })
+        };
+
+        let mut handles = Vec::with_capacity(self.tasks_order.len());
+        for &index in &self.tasks_order {
+            let tasks = Arc::clone(self);
+            handles.push(tokio::spawn(async move { tasks.run_one(index).await }));
+        }
+        for handle in handles {
+            let _ = handle.await;
+        }
+
+        control_loop.abort();
+
+        let mut outputs = Outputs::new();
+        let mut snapshot = Vec::with_capacity(self.tasks_order.len());
+        for &index in &self.tasks_order {
+            let node = self.graph[index].read().await;
+            if let TaskStatus::Completed(completed) = &node.status {
+                if let TaskCompleted::Success(_, output) = completed {
+                    outputs.insert(node.task.name.clone(), output.clone());
+                }
+                snapshot.push(PersistedTask {
+                    name: node.task.name.clone(),
+                    state: PersistedState::from_completed(completed),
+                });
+            }
+        }
+        self.persist_snapshot(&snapshot);
+
+        outputs
+    }
+
+    /// Best-effort write of every completed task's status to `db_path`, so
+    /// the next `Tasks::build` (i.e. `devenv tasks status`) can read it back.
+    /// Failure to write is silently ignored -- a missing status file just
+    /// means the next `status` call sees nothing persisted yet, the same as
+    /// before this task ever ran.
+    fn persist_snapshot(&self, snapshot: &[PersistedTask]) {
+        let Some(path) = &self.db_path else {
+            return;
+        };
+        if let Ok(json) = serde_json::to_string_pretty(snapshot) {
+            let _ = std::fs::write(path, json);
+        }
+    }
+
+    /// Drive a single task: wait for its dependencies to settle, then run,
+    /// skip, dependency-fail, or cancel it as appropriate, notifying the UI
+    /// on every transition.
+    async fn run_one(&self, index: NodeIndex) {
+        while !self.dependencies_settled(index).await {
+            self.notify_ui.notified().await;
+        }
+
+        if self.dependency_failed(index).await {
+            self.graph[index].write().await.status =
+                TaskStatus::Completed(TaskCompleted::DependencyFailed);
+            self.notify_ui.notify_waiters();
+            return;
+        }
+
+        // A cancel requested while still pending skips straight to
+        // `Cancelled` without ever starting the task's command.
+        if matches!(
+            self.graph[index].read().await.status,
+            TaskStatus::Cancelling(_) | TaskStatus::Completed(TaskCompleted::Cancelled)
+        ) {
+            self.graph[index].write().await.status =
+                TaskStatus::Completed(TaskCompleted::Cancelled);
+            self.notify_ui.notify_waiters();
+            return;
+        }
+
+        let started = Instant::now();
+        self.graph[index].write().await.status = TaskStatus::Running(started);
+        self.notify_ui.notify_waiters();
+
+        let command = self.graph[index].read().await.task.command.clone();
+        let Some(command) = command else {
+            self.graph[index].write().await.status =
+                TaskStatus::Completed(TaskCompleted::Skipped(Skipped::NotImplemented));
+            self.notify_ui.notify_waiters();
+            return;
+        };
+
+        let cancel = Arc::clone(&self.graph[index].read().await.cancel);
+        let outcome = self.run_command(index, &command, cancel).await;
+
+        // An abort may already have written `Completed(Cancelled)` to this
+        // node directly from `apply_transition_all` while `run_command` was
+        // still waiting for the killed child to actually exit. Don't clobber
+        // that with whatever `run_command` returns once it catches up.
+        let mut node = self.graph[index].write().await;
+        if !matches!(node.status, TaskStatus::Completed(_)) {
+            node.status = match outcome {
+                CommandOutcome::Success(output) => {
+                    TaskStatus::Completed(TaskCompleted::Success(started.elapsed(), output))
+                }
+                CommandOutcome::Failed(failure) => {
+                    TaskStatus::Completed(TaskCompleted::Failed(started.elapsed(), failure))
+                }
+                CommandOutcome::Cancelled => TaskStatus::Completed(TaskCompleted::Cancelled),
+            };
+        }
+        drop(node);
+        self.notify_ui.notify_waiters();
+    }
+
+    /// Run the task's command to completion, capturing its output and
+    /// streaming each line into the node's `recent_output` ring buffer as it
+    /// arrives so `--follow` shows it live.
+    ///
+    /// Pause is cooperative bookkeeping only: we don't send the child process
+    /// a stop signal, we just stop showing the task as actively running in
+    /// the UI until it's resumed. Cancel and abort both actually kill the
+    /// child (there's no separate graceful-shutdown signal without pulling in
+    /// a signal-handling dependency we don't otherwise need) as soon as
+    /// `cancel` fires, then keep draining output until the now-dying child
+    /// exits so the capture stays complete.
+    async fn run_command(
+        &self,
+        index: NodeIndex,
+        command: &[String],
+        cancel: Arc<Notify>,
+    ) -> CommandOutcome {
+        let Some((program, args)) = command.split_first() else {
+            return CommandOutcome::Success(Outputs::new());
+        };
+
+        let mut child = match Command::new(program)
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) => {
+                return CommandOutcome::Failed(Failure {
+                    error: err.to_string(),
+                    stdout: vec![],
+                    stderr: vec![],
+                    exit_code: None,
+                });
+            }
+        };
+
+        let mut stdout = BufReader::new(child.stdout.take().expect("piped stdout")).lines();
+        let mut stderr = BufReader::new(child.stderr.take().expect("piped stderr")).lines();
+        let mut captured_stdout = Vec::new();
+        let mut captured_stderr = Vec::new();
+        let mut stdout_done = false;
+        let mut stderr_done = false;
+        let mut cancelling = false;
+
+        loop {
+            tokio::select! {
+                line = stdout.next_line(), if !stdout_done => match line {
+                    Ok(Some(line)) => {
+                        self.graph[index]
+                            .write()
+                            .await
+                            .push_output(OutputStream::Stdout, line.clone());
+                        self.notify_ui.notify_waiters();
+                        captured_stdout.push((Instant::now(), line));
+                    }
+                    Ok(None) | Err(_) => stdout_done = true,
+                },
+                line = stderr.next_line(), if !stderr_done => match line {
+                    Ok(Some(line)) => {
+                        self.graph[index]
+                            .write()
+                            .await
+                            .push_output(OutputStream::Stderr, line.clone());
+                        self.notify_ui.notify_waiters();
+                        captured_stderr.push((Instant::now(), line));
+                    }
+                    Ok(None) | Err(_) => stderr_done = true,
+                },
+                _ = cancel.notified(), if !cancelling => {
+                    cancelling = true;
+                    let _ = child.start_kill();
+                },
+                status = child.wait(), if stdout_done && stderr_done => {
+                    if cancelling {
+                        return CommandOutcome::Cancelled;
+                    }
+                    let status = match status {
+                        Ok(status) => status,
+                        Err(err) => {
+                            return CommandOutcome::Failed(Failure {
+                                error: err.to_string(),
+                                stdout: captured_stdout,
+                                stderr: captured_stderr,
+                                exit_code: None,
+                            });
+                        }
+                    };
+                    return if status.success() {
+                        CommandOutcome::Success(Outputs::new())
+                    } else {
+                        CommandOutcome::Failed(Failure {
+                            error: format!("command exited with {status}"),
+                            stdout: captured_stdout,
+                            stderr: captured_stderr,
+                            exit_code: status.code(),
+                        })
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// How a task's command finished: completed on its own (successfully or
+/// not), or was killed in response to a cancel/abort request.
+enum CommandOutcome {
+    Success(Outputs),
+    Failed(Failure),
+    Cancelled,
+}
+
+/// One task's on-disk record at `db_path`: enough to reconstruct a terminal
+/// [`TaskStatus`] on the next `Tasks::build`, without any of the transient
+/// detail (captured output, timing) that only matters while a run is live.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedTask {
+    name: String,
+    state: PersistedState,
+}
+
+/// The persisted shape of a [`TaskCompleted`]. Kept separate from
+/// `TaskCompleted` itself since that type carries data (raw output,
+/// `Instant`s) that doesn't survive a process restart and isn't needed to
+/// report a task's outcome after the fact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum PersistedState {
+    Succeeded,
+    Failed {
+        error: String,
+        exit_code: Option<i32>,
+    },
+    Skipped,
+    DependencyFailed,
+    Cancelled,
+}
+
+impl PersistedState {
+    fn from_completed(completed: &TaskCompleted) -> Self {
+        match completed {
+            TaskCompleted::Success(_, _) => PersistedState::Succeeded,
+            TaskCompleted::Failed(_, failure) => PersistedState::Failed {
+                error: failure.error.clone(),
+                exit_code: failure.exit_code,
+            },
+            TaskCompleted::Skipped(_) => PersistedState::Skipped,
+            TaskCompleted::DependencyFailed => PersistedState::DependencyFailed,
+            TaskCompleted::Cancelled => PersistedState::Cancelled,
+        }
+    }
+
+    fn into_task_status(self) -> TaskStatus {
+        let completed = match self {
+            PersistedState::Succeeded => TaskCompleted::Success(Duration::ZERO, Outputs::new()),
+            PersistedState::Failed { error, exit_code } => TaskCompleted::Failed(
+                Duration::ZERO,
+                Failure {
+                    error,
+                    stdout: vec![],
+                    stderr: vec![],
+                    exit_code,
+                },
+            ),
+            PersistedState::Skipped => TaskCompleted::Skipped(Skipped::Cached(Outputs::new())),
+            PersistedState::DependencyFailed => TaskCompleted::DependencyFailed,
+            PersistedState::Cancelled => TaskCompleted::Cancelled,
+        };
+        TaskStatus::Completed(completed)
+    }
+}
+
+/// Best-effort read of a previous run's persisted statuses. Any failure --
+/// missing file, unreadable, stale/incompatible format -- just yields no
+/// seeded statuses, the same as a task that's never been run.
+fn load_snapshot(path: &std::path::Path) -> Vec<PersistedTask> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Notify;
+
+use crate::Outputs;
+
+/// Maximum number of interleaved stdout/stderr lines kept per task for
+/// `--follow` and post-mortem inspection, oldest dropped first.
+pub(crate) const RECENT_OUTPUT_CAPACITY: usize = 200;
+
+/// A task as declared in `devenv.nix`, before it's ever run.
+#[derive(Debug, Clone)]
+pub struct Task {
+    pub name: String,
+    /// The command to run, as `argv`. `None` for a grouping/no-op task,
+    /// which completes immediately as [`Skipped::NotImplemented`].
+    pub command: Option<Vec<String>>,
+}
+
+/// One line of captured task output, tagged with which stream it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// Details captured when a task's own run fails, as opposed to one of its
+/// dependencies failing first.
+#[derive(Debug, Clone)]
+pub struct Failure {
+    pub error: String,
+    pub stdout: Vec<(Instant, String)>,
+    pub stderr: Vec<(Instant, String)>,
+    pub exit_code: Option<i32>,
+}
+
+/// Why a task was skipped instead of actually running.
+#[derive(Debug, Clone)]
+pub enum Skipped {
+    /// A previous run's outputs were reused because its inputs haven't changed.
+    Cached(Outputs),
+    /// The task has no command to run.
+    NotImplemented,
+}
+
+/// The outcome of a task that has finished running, in whichever way it finished.
+#[derive(Debug, Clone)]
+pub enum TaskCompleted {
+    Success(Duration, Outputs),
+    Failed(Duration, Failure),
+    Skipped(Skipped),
+    DependencyFailed,
+    Cancelled,
+}
+
+/// A task's state machine. Transitions are delivered as [`TaskControl`]
+/// requests over [`crate::tasks::Tasks::request_all`] rather than applied to
+/// a task directly, so a request that doesn't make sense from a task's
+/// current state (e.g. resuming one that isn't paused) is just a no-op.
+#[derive(Debug, Clone)]
+pub enum TaskStatus {
+    Pending,
+    Running(Instant),
+    Paused(Instant),
+    Cancelling(Instant),
+    Completed(TaskCompleted),
+}
+
+/// A request to change a task's state, delivered over a channel rather than
+/// applied directly so the run loop can decide whether the transition is
+/// valid from wherever the task currently is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskControl {
+    Pause,
+    Resume,
+    Cancel,
+    Abort,
+}
+
+/// A task's live state in the dependency graph: its declaration, current
+/// status, and a bounded tail of its own interleaved output.
+#[derive(Debug)]
+pub struct TaskNode {
+    pub task: Task,
+    pub status: TaskStatus,
+    /// Interleaved stdout/stderr, oldest-first, capped at
+    /// [`RECENT_OUTPUT_CAPACITY`] lines so a noisy or long-running task can't
+    /// grow this without bound. Read by `TasksUi::follow_lines` for `--follow`.
+    pub recent_output: VecDeque<(Instant, OutputStream, String)>,
+    /// Notified by `Tasks::apply_transition_all` when a cancel/abort request
+    /// lands on this task while it's running, so `Tasks::run_command` can
+    /// actually kill the child instead of letting it run to completion.
+    pub(crate) cancel: Arc<Notify>,
+}
+
+impl TaskNode {
+    pub(crate) fn new(task: Task) -> Self {
+        Self {
+            task,
+            status: TaskStatus::Pending,
+            recent_output: VecDeque::new(),
+            cancel: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Append one line of output, dropping the oldest line once the ring
+    /// buffer is full.
+    pub(crate) fn push_output(&mut self, stream: OutputStream, line: String) {
+        if self.recent_output.len() >= RECENT_OUTPUT_CAPACITY {
+            self.recent_output.pop_front();
+        }
+        self.recent_output.push_back((Instant::now(), stream, line));
+    }
+}
@@ -1,18 +1,89 @@
-use console::Term;
+use console::{Key, Term};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc;
+
+use crate::types::{OutputStream, Skipped, TaskCompleted, TaskControl, TaskStatus};
+use crate::{Config, Error, OutputFormat, Outputs, Tasks, VerbosityLevel};
+
+/// A second `Ctrl-C` within this window of the first forces an abort
+/// instead of a graceful cancel.
+const CTRL_C_ABORT_WINDOW: Duration = Duration::from_secs(2);
+
+/// Number of trailing output lines shown for the followed task.
+const FOLLOW_TAIL_LINES: usize = 20;
+
+/// Default tick for coalescing `notify_ui` notifications into a redraw, and
+/// the interval the adaptive backoff resets to the moment a task transitions.
+const DEFAULT_REDRAW_INTERVAL: Duration = Duration::from_millis(75);
+
+/// Ceiling for the adaptive redraw backoff once the graph has gone quiet.
+const MAX_REDRAW_INTERVAL: Duration = Duration::from_millis(750);
+
+/// A task's state as exposed by `devenv tasks status`, a coarser view than
+/// the TUI's own [`TaskStatus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskState {
+    /// Running, paused, or in the process of being cancelled.
+    Active,
+    /// Still pending, waiting on unmet dependencies.
+    Idle,
+    /// Finished successfully, or was skipped.
+    Done,
+    /// Failed, had a dependency fail, or was cancelled.
+    Dead,
+}
+
+/// A snapshot of a single task shared by the TUI's status table and by
+/// `devenv tasks status`, so a previous or ongoing run can be inspected
+/// without re-running anything.
+#[derive(Debug, Clone)]
+pub struct TaskRecord {
+    pub name: String,
+    pub status: TaskStatus,
+    pub state: TaskState,
+    pub duration: Option<Duration>,
+    /// Set when `state` is `Dead` because a dependency failed.
+    pub dependency_failure: Option<String>,
+    /// Set when `state` is `Dead` because the task's own run failed.
+    pub error: Option<String>,
+}
 
-use crate::types::{Skipped, TaskCompleted, TaskStatus};
-use crate::{Config, Error, Outputs, Tasks, VerbosityLevel};
+/// Coarse per-task status label used for the redraw-throttle hash. Distinct
+/// from the rendered status text in that it ignores the live elapsed-time
+/// duration, so a running task's hash doesn't change every tick merely
+/// because time passed.
+fn status_kind(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Pending => "pending",
+        TaskStatus::Running(_) => "running",
+        TaskStatus::Paused(_) => "paused",
+        TaskStatus::Cancelling(_) => "cancelling",
+        TaskStatus::Completed(TaskCompleted::Success(_, _)) => "succeeded",
+        TaskStatus::Completed(TaskCompleted::Failed(_, _)) => "failed",
+        TaskStatus::Completed(TaskCompleted::Skipped(Skipped::Cached(_))) => "cached",
+        TaskStatus::Completed(TaskCompleted::Skipped(Skipped::NotImplemented)) => "not_implemented",
+        TaskStatus::Completed(TaskCompleted::DependencyFailed) => "dependency_failed",
+        TaskStatus::Completed(TaskCompleted::Cancelled) => "cancelled",
+    }
+}
 
 /// Status information for all tasks
 pub struct TasksStatus {
     lines: Vec<String>,
+    /// `(task name, status_kind)` per non-pending task, used only to detect real status
+    /// transitions for the redraw-throttle hash — see [`TasksStatus::content_hash`].
+    status_kinds: Vec<(String, &'static str)>,
     pub pending: usize,
     pub running: usize,
+    pub paused: usize,
+    pub cancelling: usize,
     pub succeeded: usize,
     pub failed: usize,
     pub skipped: usize,
+    pub cancelled: usize,
     pub dependency_failed: usize,
 }
 
@@ -20,14 +91,31 @@ impl TasksStatus {
     fn new() -> Self {
         Self {
             lines: vec![],
+            status_kinds: vec![],
             pending: 0,
             running: 0,
+            paused: 0,
+            cancelling: 0,
             succeeded: 0,
             failed: 0,
             skipped: 0,
+            cancelled: 0,
             dependency_failed: 0,
         }
     }
+
+    /// Hash the per-task status kinds, so the run loop can tell whether any
+    /// task actually changed status between ticks. Deliberately ignores
+    /// `self.lines`, which embeds a live elapsed-time string for
+    /// running/paused/cancelling tasks and would otherwise change on
+    /// virtually every poll while work is in flight.
+    fn content_hash(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.status_kinds.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 /// UI manager for tasks
@@ -35,6 +123,13 @@ pub struct TasksUi {
     tasks: Arc<Tasks>,
     verbosity: VerbosityLevel,
     term: Term,
+    /// Where `OutputFormat::Json` events are written. The status table and
+    /// errors always go to `term` (stderr); NDJSON goes to stdout so CI and
+    /// editor tooling can parse a clean, stable stream on it.
+    json_term: Term,
+    follow: Option<String>,
+    format: OutputFormat,
+    redraw_interval: Duration,
 }
 
 impl TasksUi {
@@ -46,6 +141,10 @@ impl TasksUi {
             tasks: Arc::new(tasks),
             verbosity,
             term: Term::stderr(),
+            json_term: Term::stdout(),
+            follow: None,
+            format: OutputFormat::Text,
+            redraw_interval: DEFAULT_REDRAW_INTERVAL,
         })
     }
 
@@ -61,27 +160,130 @@ impl TasksUi {
             tasks: Arc::new(tasks),
             verbosity,
             term: Term::stderr(),
+            json_term: Term::stdout(),
+            follow: None,
+            format: OutputFormat::Text,
+            redraw_interval: DEFAULT_REDRAW_INTERVAL,
         })
     }
 
-    async fn get_tasks_status(&self) -> TasksStatus {
-        let mut tasks_status = TasksStatus::new();
+    /// Tail the given task's live stdout/stderr at the bottom of the TUI
+    /// instead of only showing its output if it fails. Used by `--follow`.
+    pub fn set_follow(&mut self, task: Option<String>) {
+        self.follow = task;
+    }
+
+    /// Select how status updates are reported. `OutputFormat::Json` swaps the
+    /// ANSI status table for a newline-delimited JSON event stream on stdout,
+    /// for CI and editor tooling to consume.
+    pub fn set_output_format(&mut self, format: OutputFormat) {
+        self.format = format;
+    }
+
+    /// Base tick for coalescing `notify_ui` notifications into a redraw. The
+    /// run loop adaptively backs off past this once the graph goes quiet, so
+    /// tune it down for snappier feedback on small graphs or up to cut CPU
+    /// use on large ones.
+    pub fn set_redraw_interval(&mut self, interval: Duration) {
+        self.redraw_interval = interval;
+    }
+
+    /// Read the task graph once and return a record per task: its coarse
+    /// state (active/idle/done/dead), full [`TaskStatus`], accumulated run
+    /// time, and — when blocked or failed — why. [`get_tasks_status`] and
+    /// [`format_task_errors`] both build on this instead of each doing their
+    /// own pass over `tasks_order`, and `devenv tasks status` uses it
+    /// directly to inspect a previous or ongoing run without re-running
+    /// anything.
+    ///
+    /// [`get_tasks_status`]: Self::get_tasks_status
+    /// [`format_task_errors`]: Self::format_task_errors
+    pub async fn task_records(&self) -> Vec<TaskRecord> {
+        let mut records = Vec::with_capacity(self.tasks.tasks_order.len());
 
         for index in &self.tasks.tasks_order {
-            let (task_status, task_name) = {
-                let task_state = self.tasks.graph[*index].read().await;
-                (task_state.status.clone(), task_state.task.name.clone())
+            let task_state = self.tasks.graph[*index].read().await;
+            let name = task_state.task.name.clone();
+            let status = task_state.status.clone();
+
+            let (state, duration, dependency_failure, error) = match &status {
+                TaskStatus::Pending => (TaskState::Idle, None, None, None),
+                TaskStatus::Running(started) => {
+                    (TaskState::Active, Some(started.elapsed()), None, None)
+                }
+                TaskStatus::Paused(started) => {
+                    (TaskState::Active, Some(started.elapsed()), None, None)
+                }
+                TaskStatus::Cancelling(started) => {
+                    (TaskState::Active, Some(started.elapsed()), None, None)
+                }
+                TaskStatus::Completed(TaskCompleted::Success(duration, _)) => {
+                    (TaskState::Done, Some(*duration), None, None)
+                }
+                TaskStatus::Completed(TaskCompleted::Skipped(_)) => {
+                    (TaskState::Done, None, None, None)
+                }
+                TaskStatus::Completed(TaskCompleted::Failed(duration, failure)) => (
+                    TaskState::Dead,
+                    Some(*duration),
+                    None,
+                    Some(failure.error.clone()),
+                ),
+                TaskStatus::Completed(TaskCompleted::DependencyFailed) => (
+                    TaskState::Dead,
+                    None,
+                    Some("one or more dependencies failed".to_string()),
+                    None,
+                ),
+                TaskStatus::Completed(TaskCompleted::Cancelled) => {
+                    (TaskState::Dead, None, None, None)
+                }
             };
-            let (status_text, duration) = match task_status {
+
+            records.push(TaskRecord {
+                name,
+                status,
+                state,
+                duration,
+                dependency_failure,
+                error,
+            });
+        }
+
+        records
+    }
+
+    async fn get_tasks_status(&self) -> TasksStatus {
+        let mut tasks_status = TasksStatus::new();
+
+        for record in self.task_records().await {
+            let kind = status_kind(&record.status);
+            let (status_text, duration) = match record.status {
                 TaskStatus::Pending => {
                     tasks_status.pending += 1;
                     continue;
                 }
-                TaskStatus::Running(started) => {
+                TaskStatus::Running(_) => {
                     tasks_status.running += 1;
                     (
                         console::style(format!("{:17}", "Running")).blue().bold(),
-                        Some(started.elapsed()),
+                        record.duration,
+                    )
+                }
+                TaskStatus::Paused(_) => {
+                    tasks_status.paused += 1;
+                    (
+                        console::style(format!("{:17}", "Paused")).yellow().bold(),
+                        record.duration,
+                    )
+                }
+                TaskStatus::Cancelling(_) => {
+                    tasks_status.cancelling += 1;
+                    (
+                        console::style(format!("{:17}", "Cancelling"))
+                            .yellow()
+                            .bold(),
+                        record.duration,
                     )
                 }
                 TaskStatus::Completed(TaskCompleted::Skipped(skipped)) => {
@@ -92,18 +294,18 @@ impl TasksUi {
                     };
                     (console::style(format!("{:17}", status)).blue().bold(), None)
                 }
-                TaskStatus::Completed(TaskCompleted::Success(duration, _)) => {
+                TaskStatus::Completed(TaskCompleted::Success(_, _)) => {
                     tasks_status.succeeded += 1;
                     (
                         console::style(format!("{:17}", "Succeeded")).green().bold(),
-                        Some(duration),
+                        record.duration,
                     )
                 }
-                TaskStatus::Completed(TaskCompleted::Failed(duration, _)) => {
+                TaskStatus::Completed(TaskCompleted::Failed(_, _)) => {
                     tasks_status.failed += 1;
                     (
                         console::style(format!("{:17}", "Failed")).red().bold(),
-                        Some(duration),
+                        record.duration,
                     )
                 }
                 TaskStatus::Completed(TaskCompleted::DependencyFailed) => {
@@ -115,6 +317,13 @@ impl TasksUi {
                         None,
                     )
                 }
+                TaskStatus::Completed(TaskCompleted::Cancelled) => {
+                    tasks_status.cancelled += 1;
+                    (
+                        console::style(format!("{:17}", "Cancelled")).red().bold(),
+                        None,
+                    )
+                }
             };
 
             let duration = match duration {
@@ -122,10 +331,12 @@ impl TasksUi {
                 None => "".to_string(),
             };
 
+            tasks_status.status_kinds.push((record.name.clone(), kind));
+
             tasks_status.lines.push(format!(
                 "{} {:40} {:10}",
                 status_text,
-                console::style(task_name).bold(),
+                console::style(record.name).bold(),
                 duration
             ));
         }
@@ -142,9 +353,15 @@ impl TasksUi {
         if self.verbosity == VerbosityLevel::Quiet {
             loop {
                 let tasks_status = self.get_tasks_status().await;
-                if tasks_status.pending == 0 && tasks_status.running == 0 {
+                if tasks_status.pending == 0
+                    && tasks_status.running == 0
+                    && tasks_status.paused == 0
+                    && tasks_status.cancelling == 0
+                {
                     break;
                 }
+                // Wait for the next transition instead of busy-spinning.
+                self.tasks.notify_ui.notified().await;
             }
 
             // Print errors even in quiet mode
@@ -160,8 +377,11 @@ impl TasksUi {
 
         let names = console::style(self.tasks.root_names.join(", ")).bold();
 
-        // Disable TUI in verbose mode to prevent it from overwriting task output
-        let is_tty = self.term.is_term() && self.verbosity != VerbosityLevel::Verbose;
+        // Disable TUI in verbose mode to prevent it from overwriting task output,
+        // and always use the non-interactive path for JSON output.
+        let is_tty = self.format == OutputFormat::Text
+            && self.term.is_term()
+            && self.verbosity != VerbosityLevel::Verbose;
 
         // Always show which tasks are being run
         self.console_write_line(&format!("{:17} {}\n", "Running tasks", names))?;
@@ -173,6 +393,30 @@ impl TasksUi {
         // This prevents the TUI from overwriting stdout/stderr in verbose mode
         let mut last_list_height: u16 = 0;
         let mut last_statuses = std::collections::HashMap::new();
+        let mut last_ctrl_c: Option<Instant> = None;
+
+        // Coalesce bursts of `notify_ui` notifications into at most one
+        // redraw per tick. The tick backs off adaptively while nothing
+        // changes (to avoid locking every node's RwLock and redrawing the
+        // whole screen on graphs with many fast tasks) and resets to
+        // `redraw_interval` the moment a task actually transitions.
+        let mut redraw_delay = self.redraw_interval;
+        let mut last_status_hash: Option<u64> = None;
+
+        // Read terminal key presses on a blocking thread and forward them
+        // over a channel so the run loop can select on them alongside
+        // `notify_ui`, without blocking task-status redraws.
+        let (key_tx, mut key_rx) = mpsc::unbounded_channel::<Key>();
+        if is_tty {
+            let term = self.term.clone();
+            tokio::task::spawn_blocking(move || {
+                while let Ok(key) = term.read_key() {
+                    if key_tx.send(key).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
 
         loop {
             let tasks_status = self.get_tasks_status().await;
@@ -195,6 +439,24 @@ impl TasksUi {
                 } else {
                     String::new()
                 },
+                if tasks_status.paused > 0 {
+                    format!(
+                        "{} {}",
+                        tasks_status.paused,
+                        console::style("Paused").yellow().bold()
+                    )
+                } else {
+                    String::new()
+                },
+                if tasks_status.cancelling > 0 {
+                    format!(
+                        "{} {}",
+                        tasks_status.cancelling,
+                        console::style("Cancelling").yellow().bold()
+                    )
+                } else {
+                    String::new()
+                },
                 if tasks_status.skipped > 0 {
                     format!(
                         "{} {}",
@@ -231,59 +493,122 @@ impl TasksUi {
                 } else {
                     String::new()
                 },
+                if tasks_status.cancelled > 0 {
+                    format!(
+                        "{} {}",
+                        tasks_status.cancelled,
+                        console::style("Cancelled").red().bold()
+                    )
+                } else {
+                    String::new()
+                },
             ]
             .into_iter()
             .filter(|s| !s.is_empty())
             .collect::<Vec<_>>()
             .join(", ");
 
+            // Fold the followed task's output tail into the hash too, so a
+            // redraw isn't gated purely on status_kind (which stays
+            // "running" line-after-line) and --follow's live tail doesn't
+            // stall out exactly while there's something left to follow.
+            let follow_snapshot = self.follow_lines().await;
+            let status_hash = {
+                use std::hash::{Hash, Hasher};
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                tasks_status.content_hash().hash(&mut hasher);
+                follow_snapshot.hash(&mut hasher);
+                hasher.finish()
+            };
+            let status_changed = last_status_hash != Some(status_hash);
+            last_status_hash = Some(status_hash);
+            redraw_delay = if status_changed {
+                self.redraw_interval
+            } else {
+                (redraw_delay * 2).min(MAX_REDRAW_INTERVAL)
+            };
+
             if is_tty {
-                let elapsed_time = format!("{:.2?}", started.elapsed());
-
-                let output = format!(
-                    "{}\n{status_summary}{}{elapsed_time}",
-                    tasks_status.lines.join("\n"),
-                    " ".repeat(
-                        (19 + self.tasks.longest_task_name)
-                            .saturating_sub(console::measure_text_width(&status_summary))
-                            .max(1)
-                    )
-                );
-                if !tasks_status.lines.is_empty() {
-                    let output = console::Style::new().apply_to(output);
-                    if last_list_height > 0 {
-                        self.term.move_cursor_up(last_list_height as usize)?;
-                        self.term.clear_to_end_of_screen()?;
+                if status_changed {
+                    let elapsed_time = format!("{:.2?}", started.elapsed());
+
+                    let mut output = format!(
+                        "{}\n{status_summary}{}{elapsed_time}",
+                        tasks_status.lines.join("\n"),
+                        " ".repeat(
+                            (19 + self.tasks.longest_task_name)
+                                .saturating_sub(console::measure_text_width(&status_summary))
+                                .max(1)
+                        )
+                    );
+
+                    // Reserve a scroll region below the status list for the
+                    // followed task's live output, redrawn along with the status list.
+                    let mut extra_lines = 1u16;
+                    if let Some(follow_lines) = self.follow_lines().await {
+                        output.push('\n');
+                        output.push_str(
+                            &console::style(format!(
+                                "--- follow: {} ---",
+                                self.follow.as_deref().unwrap_or_default()
+                            ))
+                            .dim()
+                            .to_string(),
+                        );
+                        for line in &follow_lines {
+                            output.push('\n');
+                            output.push_str(line);
+                        }
+                        extra_lines += 1 + follow_lines.len() as u16;
                     }
-                    self.console_write_line(&output.to_string())?;
-                }
 
-                last_list_height = tasks_status.lines.len() as u16 + 1;
+                    if !tasks_status.lines.is_empty() {
+                        let output = console::Style::new().apply_to(output);
+                        if last_list_height > 0 {
+                            self.term.move_cursor_up(last_list_height as usize)?;
+                            self.term.clear_to_end_of_screen()?;
+                        }
+                        self.console_write_line(&output.to_string())?;
+                    }
+
+                    last_list_height = tasks_status.lines.len() as u16 + extra_lines;
+                }
             } else {
-                // Non-interactive mode - print only status changes
+                // Non-interactive mode - emit only status changes, either as the
+                // usual human-readable lines or (OutputFormat::Json) as NDJSON
+                // events. Both share the same transition detection below so
+                // events fire exactly once per change.
                 for task_state in self.tasks.graph.node_weights() {
                     let task_state = task_state.read().await;
-                    let task_name = &task_state.task.name;
-                    let current_status = match &task_state.status {
-                        TaskStatus::Pending => "Pending".to_string(),
-                        TaskStatus::Running(_) => {
-                            if let Some(previous) = last_statuses.get(task_name) {
-                                if previous != "Running" {
-                                    self.console_write_line(&format!(
-                                        "{:17} {}",
-                                        console::style("Running").blue().bold(),
-                                        console::style(task_name).bold()
-                                    ))?;
-                                }
-                            } else {
-                                self.console_write_line(&format!(
-                                    "{:17} {}",
-                                    console::style("Running").blue().bold(),
-                                    console::style(task_name).bold()
-                                ))?;
-                            }
-                            "Running".to_string()
-                        }
+                    let task_name = task_state.task.name.clone();
+                    let status = task_state.status.clone();
+
+                    let (current_status, text_line) = match &status {
+                        TaskStatus::Pending => ("Pending".to_string(), None),
+                        TaskStatus::Running(_) => (
+                            "Running".to_string(),
+                            Some(format!(
+                                "{:17} {}",
+                                console::style("Running").blue().bold(),
+                                console::style(&task_name).bold()
+                            )),
+                        ),
+                        TaskStatus::Paused(_) => (
+                            "Paused".to_string(),
+                            Some(format!(
+                                "{:17} {}",
+                                console::style("Paused").yellow().bold(),
+                                console::style(&task_name).bold()
+                            )),
+                        ),
+                        TaskStatus::Cancelling(_) => (
+                            "Cancelling".to_string(),
+                            Some(format!(
+                                "{:17} {}",
+                                console::style("Cancelling").yellow().bold(),
+                                console::style(&task_name).bold()
+                            )),
+                        ),
                         TaskStatus::Completed(completed) => {
                             let (status, style, duration_str) = match completed {
                                 TaskCompleted::Success(duration, _) => (
@@ -311,43 +636,76 @@ impl TasksUi {
                                     console::style("Dependency failed").red().bold(),
                                     "".to_string(),
                                 ),
+                                TaskCompleted::Cancelled => (
+                                    "Cancelled".to_string(),
+                                    console::style("Cancelled").red().bold(),
+                                    "".to_string(),
+                                ),
                             };
 
-                            if let Some(previous) = last_statuses.get(task_name) {
-                                if previous != &status {
-                                    self.console_write_line(&format!(
-                                        "{:17} {}{}",
-                                        style,
-                                        console::style(task_name).bold(),
-                                        duration_str
-                                    ))?;
-                                }
-                            } else {
-                                self.console_write_line(&format!(
+                            (
+                                status.clone(),
+                                Some(format!(
                                     "{:17} {}{}",
                                     style,
-                                    console::style(task_name).bold(),
+                                    console::style(&task_name).bold(),
                                     duration_str
-                                ))?;
-                            }
-                            status
+                                )),
+                            )
                         }
                     };
 
-                    last_statuses.insert(task_name.clone(), current_status);
+                    let changed = last_statuses.get(&task_name) != Some(&current_status);
+                    if changed {
+                        match self.format {
+                            OutputFormat::Text => {
+                                if let Some(line) = text_line {
+                                    self.console_write_line(&line)?;
+                                }
+                            }
+                            OutputFormat::Json => {
+                                self.emit_json_event(&task_name, &status, started)?;
+                            }
+                        }
+                    }
+
+                    last_statuses.insert(task_name, current_status);
                 }
             }
 
             // Break early if there are no more tasks left
-            if tasks_status.pending == 0 && tasks_status.running == 0 {
+            if tasks_status.pending == 0
+                && tasks_status.running == 0
+                && tasks_status.paused == 0
+                && tasks_status.cancelling == 0
+            {
                 if !is_tty {
-                    self.console_write_line(&status_summary)?;
+                    match self.format {
+                        OutputFormat::Text => self.console_write_line(&status_summary)?,
+                        OutputFormat::Json => self.emit_json_summary(&tasks_status)?,
+                    }
                 }
                 break;
             }
 
-            // Wait for task updates before looping
-            self.tasks.notify_ui.notified().await;
+            // Wait for task updates or, when interactive, a key press before looping.
+            // `notify_ui` is awaited through a cloned Arc rather than `self.tasks`
+            // directly so the other branch is free to take `&mut self`.
+            if is_tty {
+                let tasks = Arc::clone(&self.tasks);
+                tokio::select! {
+                    _ = tasks.notify_ui.notified() => {
+                        // Let the rest of this burst land, then redraw once.
+                        tokio::time::sleep(self.redraw_interval).await;
+                    },
+                    Some(key) = key_rx.recv() => {
+                        self.handle_key_press(key, &mut last_ctrl_c).await?;
+                    }
+                    _ = tokio::time::sleep(redraw_delay) => {},
+                }
+            } else {
+                self.tasks.notify_ui.notified().await;
+            }
         }
 
         let errors = self.format_task_errors().await;
@@ -365,17 +723,190 @@ impl TasksUi {
         Ok(())
     }
 
+    /// Write one line of `OutputFormat::Json` output to stdout, separate from
+    /// `console_write_line`'s stderr so the NDJSON stream stays clean.
+    fn console_write_json_line(&self, message: &str) -> std::io::Result<()> {
+        self.json_term.write_line(message)?;
+        Ok(())
+    }
+
+    /// Dispatch a key press to the running tasks.
+    ///
+    /// `p`/`r`/`c` request a pause/resume/cancel of every running task; these
+    /// are delivered as transition requests rather than direct mutations, so
+    /// a task that can't honour the request right now (e.g. it already
+    /// completed) just ignores it. A cancel kills the child process outright,
+    /// just as an abort does; the child gets no separate signal to clean up
+    /// first. A second `Ctrl-C` within [`CTRL_C_ABORT_WINDOW`] of the first
+    /// forces an abort.
+    async fn handle_key_press(
+        &mut self,
+        key: Key,
+        last_ctrl_c: &mut Option<Instant>,
+    ) -> Result<(), Error> {
+        match key {
+            Key::Char('p') => self.tasks.request_all(TaskControl::Pause).await,
+            Key::Char('r') => self.tasks.request_all(TaskControl::Resume).await,
+            Key::Char('c') => self.tasks.request_all(TaskControl::Cancel).await,
+            Key::Char('f') => {
+                self.cycle_follow().await;
+                Ok(())
+            }
+            Key::CtrlC => {
+                let now = Instant::now();
+                let double_press = last_ctrl_c
+                    .map(|previous| now.duration_since(previous) < CTRL_C_ABORT_WINDOW)
+                    .unwrap_or(false);
+                *last_ctrl_c = Some(now);
+                if double_press {
+                    self.tasks.request_all(TaskControl::Abort).await
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Cycle the followed task (`f` key) to the next running task, wrapping
+    /// around, or clear follow mode once no task is running.
+    async fn cycle_follow(&mut self) {
+        let mut running = Vec::new();
+        for index in &self.tasks.tasks_order {
+            let task_state = self.tasks.graph[*index].read().await;
+            if matches!(task_state.status, TaskStatus::Running(_)) {
+                running.push(task_state.task.name.clone());
+            }
+        }
+
+        if running.is_empty() {
+            self.follow = None;
+            return;
+        }
+
+        let next = match &self.follow {
+            Some(current) => running
+                .iter()
+                .position(|name| name == current)
+                .map(|i| (i + 1) % running.len())
+                .unwrap_or(0),
+            None => 0,
+        };
+        self.follow = Some(running[next].clone());
+    }
+
+    /// Tail of the followed task's interleaved stdout/stderr, formatted with
+    /// the same `{:07.2}` elapsed-time prefix as `format_task_errors`, followed
+    /// by an `[out]`/`[err]` stream tag: `{:07.2} [out]: {line}`.
+    async fn follow_lines(&self) -> Option<Vec<String>> {
+        let name = self.follow.as_ref()?;
+        for index in &self.tasks.tasks_order {
+            let task_state = self.tasks.graph[*index].read().await;
+            if &task_state.task.name != name {
+                continue;
+            }
+            return Some(
+                task_state
+                    .recent_output
+                    .iter()
+                    .rev()
+                    .take(FOLLOW_TAIL_LINES)
+                    .rev()
+                    .map(|(time, stream, line)| {
+                        let stream = match stream {
+                            OutputStream::Stdout => "out",
+                            OutputStream::Stderr => "err",
+                        };
+                        format!(
+                            "{:07.2} [{}]: {}",
+                            time.elapsed().as_secs_f32(),
+                            stream,
+                            line
+                        )
+                    })
+                    .collect(),
+            );
+        }
+        None
+    }
+
+    /// Emit one NDJSON status-transition event to stdout for `OutputFormat::Json`
+    /// mode: `{task, status, started_at, duration_ms, exit_code}`.
+    fn emit_json_event(
+        &self,
+        task_name: &str,
+        status: &TaskStatus,
+        run_started: Instant,
+    ) -> Result<(), Error> {
+        let (status_str, duration_ms, exit_code) = match status {
+            TaskStatus::Pending => ("pending", None, None),
+            TaskStatus::Running(_) => ("running", None, None),
+            TaskStatus::Paused(_) => ("paused", None, None),
+            TaskStatus::Cancelling(_) => ("cancelling", None, None),
+            TaskStatus::Completed(TaskCompleted::Success(duration, _)) => {
+                ("succeeded", Some(duration.as_millis()), Some(0))
+            }
+            TaskStatus::Completed(TaskCompleted::Failed(duration, failure)) => {
+                ("failed", Some(duration.as_millis()), failure.exit_code)
+            }
+            TaskStatus::Completed(TaskCompleted::Skipped(_)) => ("skipped", None, None),
+            TaskStatus::Completed(TaskCompleted::DependencyFailed) => {
+                ("dependency_failed", None, None)
+            }
+            TaskStatus::Completed(TaskCompleted::Cancelled) => ("cancelled", None, None),
+        };
+
+        let started_at = match status {
+            TaskStatus::Running(started)
+            | TaskStatus::Paused(started)
+            | TaskStatus::Cancelling(started) => {
+                Some(started.duration_since(run_started).as_secs_f64())
+            }
+            _ => None,
+        };
+
+        let event = serde_json::json!({
+            "task": task_name,
+            "status": status_str,
+            "started_at": started_at,
+            "duration_ms": duration_ms,
+            "exit_code": exit_code,
+        });
+
+        self.console_write_json_line(&event.to_string())?;
+        Ok(())
+    }
+
+    /// Emit the final NDJSON summary object, echoing the same counts shown by
+    /// the text status summary line.
+    fn emit_json_summary(&self, tasks_status: &TasksStatus) -> Result<(), Error> {
+        let summary = serde_json::json!({
+            "event": "summary",
+            "pending": tasks_status.pending,
+            "running": tasks_status.running,
+            "paused": tasks_status.paused,
+            "cancelling": tasks_status.cancelling,
+            "succeeded": tasks_status.succeeded,
+            "failed": tasks_status.failed,
+            "skipped": tasks_status.skipped,
+            "cancelled": tasks_status.cancelled,
+            "dependency_failed": tasks_status.dependency_failed,
+        });
+
+        self.console_write_json_line(&summary.to_string())?;
+        Ok(())
+    }
+
     /// Format error messages from failed tasks
     async fn format_task_errors(&self) -> String {
         let mut errors = String::new();
-        for index in &self.tasks.tasks_order {
-            let task_state = self.tasks.graph[*index].read().await;
-            if let TaskStatus::Completed(TaskCompleted::Failed(_, failure)) = &task_state.status {
+        for record in self.task_records().await {
+            if let TaskStatus::Completed(TaskCompleted::Failed(_, failure)) = &record.status {
                 errors.push_str(&format!(
                     "\n--- {} failed with error: {}\n",
-                    task_state.task.name, failure.error
+                    record.name, failure.error
                 ));
-                errors.push_str(&format!("--- {} stdout:\n", task_state.task.name));
+                errors.push_str(&format!("--- {} stdout:\n", record.name));
                 for (time, line) in &failure.stdout {
                     errors.push_str(&format!(
                         "{:07.2}: {}\n",
@@ -383,7 +914,7 @@ impl TasksUi {
                         line
                     ));
                 }
-                errors.push_str(&format!("--- {} stderr:\n", task_state.task.name));
+                errors.push_str(&format!("--- {} stderr:\n", record.name));
                 for (time, line) in &failure.stderr {
                     errors.push_str(&format!(
                         "{:07.2}: {}\n",